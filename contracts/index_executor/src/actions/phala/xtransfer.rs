@@ -2,6 +2,7 @@ use alloc::{vec, vec::Vec};
 use scale::{Decode, Encode};
 
 use crate::call::{Call, CallBuilder, CallParams, SubCall, SubExtrinsic};
+use crate::error::IndexError;
 use crate::step::Step;
 
 use crate::utils::ToArray;
@@ -29,13 +30,18 @@ impl XTransferXcm {
 }
 
 impl CallBuilder for XTransferXcm {
-    fn build_call(&self, step: Step) -> Result<Vec<Call>, &'static str> {
-        let recipient = step.recipient.ok_or("MissingRecipient")?;
-        let asset_location: MultiLocation =
-            Decode::decode(&mut step.spend_asset.as_slice()).map_err(|_| "InvalidMultilocation")?;
+    fn build_call(&self, step: Step) -> Result<Vec<Call>, IndexError> {
+        let recipient = step
+            .recipient
+            .ok_or_else(|| IndexError::permanent("MissingRecipient"))?;
+        let asset_location: MultiLocation = Decode::decode(&mut step.spend_asset.as_slice())
+            .map_err(|_| IndexError::permanent("InvalidMultilocation"))?;
         let multi_asset = MultiAsset {
             id: AssetId::Concrete(asset_location),
-            fun: Fungibility::Fungible(step.spend_amount.ok_or("MissingSpendAmount")?),
+            fun: Fungibility::Fungible(
+                step.spend_amount
+                    .ok_or_else(|| IndexError::permanent("MissingSpendAmount"))?,
+            ),
         };
         let dest = MultiLocation::new(
             1,
@@ -1,5 +1,6 @@
 use crate::account::AccountType;
 use crate::call::{Call, CallBuilder, CallParams, SubCall, SubExtrinsic};
+use crate::error::IndexError;
 use crate::step::Step;
 use crate::utils::ToArray;
 use scale::{Decode, Encode};
@@ -27,10 +28,10 @@ impl PolkadotXcm {
 }
 
 impl CallBuilder for PolkadotXcm {
-    fn build_call(&self, step: Step) -> Result<Call, &'static str> {
+    fn build_call(&self, step: Step) -> Result<Vec<Call>, IndexError> {
         let recipient = step.recipient;
-        let asset_location: MultiLocation =
-            Decode::decode(&mut step.spend_asset.as_slice()).map_err(|_| "InvalidMultilocation")?;
+        let asset_location: MultiLocation = Decode::decode(&mut step.spend_asset.as_slice())
+            .map_err(|_| IndexError::permanent("InvalidMultilocation"))?;
         let dest = VersionedMultiLocation::V2(MultiLocation::new(
             0,
             Junctions::X1(Parachain(self.dest_chain_id)),
@@ -56,12 +57,15 @@ impl CallBuilder for PolkadotXcm {
         ));
         let assets = VersionedMultiAssets::V2(MultiAssets::from(vec![MultiAsset {
             id: AssetId::Concrete(asset_location),
-            fun: Fungibility::Fungible(step.spend_amount.ok_or("MissingSpendAmount")?),
+            fun: Fungibility::Fungible(
+                step.spend_amount
+                    .ok_or_else(|| IndexError::permanent("MissingSpendAmount"))?,
+            ),
         }]));
 
         let fee_asset_item: u32 = 0;
 
-        Ok(Call {
+        Ok(vec![Call {
             params: CallParams::Sub(SubCall {
                 calldata: SubExtrinsic {
                     pallet_id: 0x63u8,
@@ -72,7 +76,7 @@ impl CallBuilder for PolkadotXcm {
             }),
             input_call: None,
             call_index: None,
-        })
+        }])
     }
 }
 
@@ -87,7 +91,7 @@ mod tests {
             dest_chain_id: PHALA_PARACHAIN_ID,
             account_type: AccountType::Account20,
         };
-        let call = xcm
+        let calls = xcm
             .build_call(Step {
                 exe: String::from(""),
                 source_chain: String::from("Polkadot"),
@@ -106,7 +110,7 @@ mod tests {
             })
             .unwrap();
 
-        match &call.params {
+        match &calls[0].params {
             CallParams::Sub(sub_call) => {
                 println!("calldata: {:?}", hex::encode(&sub_call.calldata))
             }
@@ -0,0 +1,58 @@
+use crate::error::IndexError;
+use crate::step::Step;
+use alloc::vec::Vec;
+use scale::Encode;
+
+/// A single on-chain call built by a [`CallBuilder`] for a [`Step`], ready to be merged into a
+/// task's call sequence and submitted by its executor.
+#[derive(Clone, Debug)]
+pub struct Call {
+    pub params: CallParams,
+    /// Calldata of the preceding call in the sequence, when this call's own encoding depends on
+    /// it (e.g. a Moonbeam precompile call that forwards a prior swap's output).
+    pub input_call: Option<Vec<u8>>,
+    /// This call's position within its batch/sequence, for calls that need to reference a
+    /// sibling by index rather than by value.
+    pub call_index: Option<u8>,
+}
+
+/// Chain-family-specific encoding of a [`Call`].
+#[derive(Clone, Debug)]
+pub enum CallParams {
+    Evm(EvmCall),
+    Sub(SubCall),
+}
+
+/// An EVM call: a plain `target.calldata` invocation, optionally carrying native value.
+#[derive(Clone, Debug)]
+pub struct EvmCall {
+    pub target: [u8; 20],
+    pub calldata: Vec<u8>,
+    pub value: u128,
+}
+
+/// A Substrate extrinsic's already SCALE-encoded call bytes.
+#[derive(Clone, Debug)]
+pub struct SubCall {
+    pub calldata: Vec<u8>,
+}
+
+/// A pallet call identified by `(pallet_id, call_id)`, paired with its SCALE-encodable
+/// argument tuple. Encoding this (rather than hand-assembling the bytes) keeps the pallet/call
+/// index and the argument encoding in one place.
+#[derive(Encode)]
+pub struct SubExtrinsic<T: Encode> {
+    pub pallet_id: u8,
+    pub call_id: u8,
+    pub call: T,
+}
+
+/// Builds the on-chain call(s) for a [`Step`], translating its generic asset/recipient/amount
+/// fields into the chain- and protocol-specific calldata (an XCM instruction, a precompile
+/// call, a DEX router call, ...).
+///
+/// Returns a `Vec<Call>` rather than a single `Call` since some steps (e.g. an EVM approve
+/// followed by a swap) need more than one call submitted in sequence.
+pub trait CallBuilder {
+    fn build_call(&self, step: Step) -> Result<Vec<Call>, IndexError>;
+}
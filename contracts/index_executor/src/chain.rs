@@ -0,0 +1,43 @@
+use crate::tx::IndexerBackend;
+use alloc::string::String;
+
+/// Whether a chain's worker-account addresses are 20-byte (`AccountKey20`, EVM-style) or
+/// 32-byte (`AccountId32`, Substrate-style).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChainType {
+    Evm,
+    Sub,
+}
+
+/// Static configuration for a single chain the executor can act on.
+#[derive(Clone, Debug)]
+pub struct Chain {
+    pub name: String,
+    pub endpoint: String,
+    pub chain_type: ChainType,
+    /// Off-chain indexer URL, meaningful only when `tx_indexer_backend` is `GraphQl`.
+    pub tx_indexer_url: String,
+    /// Which [`TxIndexer`](crate::tx::TxIndexer) backend to query this chain through. Set
+    /// explicitly per chain rather than inferred from whether `tx_indexer_url` happens to be
+    /// populated, so a chain that simply hasn't had its Squid URL configured yet doesn't get
+    /// silently downgraded to the RPC-scan backend.
+    pub tx_indexer_backend: IndexerBackend,
+}
+
+impl Chain {
+    pub fn new(
+        name: String,
+        endpoint: String,
+        chain_type: ChainType,
+        tx_indexer_url: String,
+        tx_indexer_backend: IndexerBackend,
+    ) -> Self {
+        Self {
+            name,
+            endpoint,
+            chain_type,
+            tx_indexer_url,
+            tx_indexer_backend,
+        }
+    }
+}
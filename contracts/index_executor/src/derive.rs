@@ -0,0 +1,59 @@
+use crate::account::AccountInfo;
+use crate::chain::{Chain, ChainType};
+use alloc::vec::Vec;
+
+/// Derive the canonical recipient bytes for `account` on `dest_chain`.
+///
+/// `AccountInfo` already maintains parallel `account20`/`account32` representations of a
+/// worker's identity (20-byte hash-truncated for `AccountKey20` chains, 32-byte for
+/// `AccountId32` chains), so a task only needs to carry one logical identity and have each
+/// bridge hop pick the representation the destination chain expects. This removes a whole
+/// class of "wrong recipient format" misroutes when chaining hops across mixed account-type
+/// parachains.
+pub fn derive_account(account: &AccountInfo, dest_chain: &Chain) -> Vec<u8> {
+    select_account_bytes(dest_chain.chain_type, &account.account20, &account.account32)
+}
+
+/// Pick the account representation matching `chain_type`. Split out from [`derive_account`] so
+/// the selection is unit-testable without needing a full `AccountInfo`/`Chain`.
+fn select_account_bytes(chain_type: ChainType, account20: &[u8], account32: &[u8]) -> Vec<u8> {
+    match chain_type {
+        ChainType::Evm => account20.to_vec(),
+        ChainType::Sub => account32.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_account20_for_evm_chains() {
+        let account20 = [1u8; 20];
+        let account32 = [2u8; 32];
+        assert_eq!(
+            select_account_bytes(ChainType::Evm, &account20, &account32),
+            account20.to_vec()
+        );
+    }
+
+    #[test]
+    fn picks_account32_for_sub_chains() {
+        let account20 = [1u8; 20];
+        let account32 = [2u8; 32];
+        assert_eq!(
+            select_account_bytes(ChainType::Sub, &account20, &account32),
+            account32.to_vec()
+        );
+    }
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let account20 = [7u8; 20];
+        let account32 = [9u8; 32];
+        assert_eq!(
+            select_account_bytes(ChainType::Evm, &account20, &account32),
+            select_account_bytes(ChainType::Evm, &account20, &account32)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::string::{String, ToString};
+
+/// Classifies a failure by recovery semantics, so callers (the [`super::scheduler::Scheduler`]
+/// in particular) can decide whether to retry, abort the task, or halt and surface the problem
+/// loudly, instead of every fallible call collapsing into an opaque `&'static str`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexError {
+    /// Network or indexer call failed or was unavailable. Safe to retry.
+    Transient(String),
+    /// The step/config itself is invalid (bad multilocation, missing recipient, ...).
+    /// Retrying won't help; abort the task.
+    Permanent(String),
+    /// The indexer returned data that doesn't parse or doesn't add up (e.g. a confirmed tx
+    /// whose row can't be decoded). Never treat this as "not confirmed" - surface it loudly.
+    Corrupt(String),
+}
+
+impl IndexError {
+    pub fn transient(msg: impl ToString) -> Self {
+        Self::Transient(msg.to_string())
+    }
+
+    pub fn permanent(msg: impl ToString) -> Self {
+        Self::Permanent(msg.to_string())
+    }
+
+    pub fn corrupt(msg: impl ToString) -> Self {
+        Self::Corrupt(msg.to_string())
+    }
+
+    /// Whether the caller should retry the operation rather than abort or halt.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Transient(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_transient_is_retryable() {
+        assert!(IndexError::transient("CallFailed").is_retryable());
+        assert!(!IndexError::permanent("MissingChain").is_retryable());
+        assert!(!IndexError::corrupt("InvalidBody").is_retryable());
+    }
+
+    #[test]
+    fn constructors_carry_the_message() {
+        assert_eq!(
+            IndexError::transient("CallFailed"),
+            IndexError::Transient("CallFailed".to_string())
+        );
+        assert_eq!(
+            IndexError::permanent("MissingChain"),
+            IndexError::Permanent("MissingChain".to_string())
+        );
+        assert_eq!(
+            IndexError::corrupt("InvalidBody"),
+            IndexError::Corrupt("InvalidBody".to_string())
+        );
+    }
+}
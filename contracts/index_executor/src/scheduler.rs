@@ -0,0 +1,297 @@
+use crate::context::Context;
+use crate::error::IndexError;
+use crate::storage::StorageClient;
+use crate::traits::Runner;
+use alloc::{boxed::Box, vec::Vec};
+
+struct Pending {
+    nonce: u64,
+    step: Box<dyn Runner>,
+    /// Whether `step.run` has already been called for this nonce. Guards against resubmitting
+    /// a duplicate transaction every poll tick while the prior submission is merely
+    /// unconfirmed-but-broadcast, since `Runner::runnable` has no mempool awareness of its own.
+    submitted: bool,
+}
+
+/// Result of a `poll()` tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PollOutcome {
+    /// Nothing pending failed permanently this tick (there may or may not have been anything to
+    /// confirm or resubmit).
+    Progressed,
+    /// The step at `nonce` returned a [`IndexError::Permanent`] error and was dropped rather
+    /// than left to wedge every later nonce behind it forever.
+    Aborted { nonce: u64, error: IndexError },
+}
+
+/// How the cross-nonce scan in `poll`/`advance` should treat a single pending nonce this tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Decision {
+    /// Confirmed; prune it.
+    Confirmed,
+    /// Still unconfirmed, or hit a transient (retryable) failure this tick. Only the first
+    /// non-`Confirmed` nonce is ever reached,
+    /// since later nonces aren't safe to act on until the gap clears.
+    Stalled,
+    /// Failed permanently; prune it too, rather than wedging everything after it.
+    Aborted(IndexError),
+}
+
+/// A fallible step outcome that isn't a success, sorted into `IndexError`'s retry taxonomy:
+/// transient failures stall (retry next tick), permanent failures abort (the pending entry
+/// should be dropped, not retried forever). `Corrupt` is never ours to swallow here - it's
+/// propagated so it surfaces loudly instead of silently stalling or dropping the nonce.
+enum Failure {
+    Stalled,
+    Aborted(IndexError),
+}
+
+fn classify<T>(result: Result<T, IndexError>) -> Result<Result<T, Failure>, IndexError> {
+    match result {
+        Ok(value) => Ok(Ok(value)),
+        Err(error) if error.is_retryable() => Ok(Err(Failure::Stalled)),
+        Err(error @ IndexError::Permanent(_)) => Ok(Err(Failure::Aborted(error))),
+        Err(error) => Err(error),
+    }
+}
+
+impl From<Failure> for Decision {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Stalled => Decision::Stalled,
+            Failure::Aborted(error) => Decision::Aborted(error),
+        }
+    }
+}
+
+/// Walk `nonces` (sorted ascending) against their precomputed `decisions`, applying the same
+/// earliest-confirmed-prefix pruning `Scheduler::poll` does: every leading `Confirmed` nonce is
+/// prunable, the first `Stalled` or `Aborted` nonce stops the scan (an `Aborted` one is pruned
+/// too, since retrying it forever would wedge every later nonce behind it), and nothing past
+/// that point is ever inspected.
+fn advance(nonces: &[u64], decisions: &[Decision]) -> (Option<u64>, PollOutcome) {
+    let mut confirmed_through = None;
+    let mut outcome = PollOutcome::Progressed;
+    for (nonce, decision) in nonces.iter().zip(decisions) {
+        match decision {
+            Decision::Confirmed => confirmed_through = Some(*nonce),
+            Decision::Stalled => break,
+            Decision::Aborted(error) => {
+                outcome = PollOutcome::Aborted {
+                    nonce: *nonce,
+                    error: error.clone(),
+                };
+                break;
+            }
+        }
+    }
+    (confirmed_through, outcome)
+}
+
+/// Owns nonce allocation for a worker account so several independent steps (e.g. a swap
+/// followed by a bridge to a different parachain) can be submitted back-to-back instead of
+/// blocking on each other's confirmation.
+///
+/// Nonces are confirmed strictly in order: if the earliest pending nonce stalls, later
+/// nonces are left untouched even if their own tx already landed, mirroring how ordered-nonce
+/// account schedulers refuse to skip a gap.
+pub struct Scheduler {
+    next_nonce: u64,
+    pending: Vec<Pending>,
+}
+
+impl Scheduler {
+    pub fn new(starting_nonce: u64) -> Self {
+        Self {
+            next_nonce: starting_nonce,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Allocate the next nonce for `step` and record it as pending.
+    pub fn submit(&mut self, step: Box<dyn Runner>) -> u64 {
+        let nonce = self.next_nonce;
+        self.next_nonce += 1;
+        self.pending.push(Pending {
+            nonce,
+            step,
+            submitted: false,
+        });
+        nonce
+    }
+
+    /// Poll pending steps, pruning the ones confirmed since the last poll and submitting the
+    /// earliest still-pending step if it has not been submitted yet. A submission that is
+    /// dropped without ever landing is not submitted again.
+    ///
+    /// Only the earliest pending nonce is allowed to advance the scheduler: a gap there means
+    /// later nonces' funds assumptions aren't safe to act on yet, so they're left alone until
+    /// it clears. A step is only ever submitted once: once `run` has fired for a nonce, later
+    /// poll ticks watch `check` for confirmation but don't resubmit, since an unconfirmed tx
+    /// may simply still be sitting in the mempool.
+    ///
+    /// A step whose `check`/`runnable`/`run` comes back [`IndexError::Permanent`] (a deregistered
+    /// chain, a fee that underflows, ...) is dropped instead of left pending: retrying it every
+    /// tick would never succeed, and leaving it in place would wedge every later nonce behind it
+    /// forever. [`IndexError::Corrupt`] is never swallowed this way - it propagates so the
+    /// problem surfaces loudly rather than being quietly stalled or dropped.
+    pub fn poll(
+        &mut self,
+        context: &Context,
+        client: Option<&StorageClient>,
+    ) -> Result<PollOutcome, IndexError> {
+        self.pending.sort_by_key(|pending| pending.nonce);
+
+        let mut nonces = Vec::with_capacity(self.pending.len());
+        let mut decisions = Vec::with_capacity(self.pending.len());
+
+        for pending in self.pending.iter_mut() {
+            nonces.push(pending.nonce);
+
+            let confirmed = match classify(pending.step.check(pending.nonce, context))? {
+                Ok(confirmed) => confirmed,
+                Err(failure) => {
+                    decisions.push(failure.into());
+                    break;
+                }
+            };
+            if confirmed {
+                decisions.push(Decision::Confirmed);
+                continue;
+            }
+
+            let runnable = match classify(pending.step.runnable(pending.nonce, context, client))? {
+                Ok(runnable) => runnable,
+                Err(failure) => {
+                    decisions.push(failure.into());
+                    break;
+                }
+            };
+            if should_resubmit(pending.submitted, runnable) {
+                match classify(pending.step.run(pending.nonce, context))? {
+                    Ok(_) => pending.submitted = true,
+                    Err(failure) => {
+                        decisions.push(failure.into());
+                        break;
+                    }
+                }
+            }
+            decisions.push(Decision::Stalled);
+            break;
+        }
+
+        let (confirmed_through, outcome) = advance(&nonces, &decisions);
+
+        self.pending.retain(|pending| {
+            if confirmed_through.is_some_and(|nonce| pending.nonce <= nonce) {
+                return false;
+            }
+            if let PollOutcome::Aborted { nonce, .. } = &outcome {
+                if pending.nonce == *nonce {
+                    return false;
+                }
+            }
+            true
+        });
+
+        Ok(outcome)
+    }
+
+    /// Number of steps still awaiting confirmation.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// Whether an unconfirmed pending step should be (re)submitted: only the first time around,
+/// never while a prior submission for the same nonce is still outstanding.
+fn should_resubmit(already_submitted: bool, runnable: bool) -> bool {
+    !already_submitted && runnable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resubmits_only_when_not_yet_submitted() {
+        assert!(should_resubmit(false, true));
+        assert!(!should_resubmit(true, true));
+    }
+
+    #[test]
+    fn never_resubmits_when_not_runnable() {
+        assert!(!should_resubmit(false, false));
+        assert!(!should_resubmit(true, false));
+    }
+
+    #[test]
+    fn classify_retries_transient_failures() {
+        let failure = match classify::<()>(Err(IndexError::transient("CallFailed"))).unwrap() {
+            Err(failure) => failure,
+            Ok(_) => panic!("expected a failure"),
+        };
+        assert!(matches!(Decision::from(failure), Decision::Stalled));
+    }
+
+    #[test]
+    fn classify_aborts_permanent_failures() {
+        let failure = match classify::<()>(Err(IndexError::permanent("MissingChain"))).unwrap() {
+            Err(failure) => failure,
+            Ok(_) => panic!("expected a failure"),
+        };
+        assert!(matches!(Decision::from(failure), Decision::Aborted(_)));
+    }
+
+    #[test]
+    fn classify_propagates_corrupt_failures() {
+        assert_eq!(
+            classify::<()>(Err(IndexError::corrupt("InvalidBody"))).unwrap_err(),
+            IndexError::corrupt("InvalidBody")
+        );
+    }
+
+    #[test]
+    fn advance_prunes_a_confirmed_prefix_and_stops_at_the_first_gap() {
+        let nonces = [1, 2, 3, 4];
+        let decisions = [
+            Decision::Confirmed,
+            Decision::Confirmed,
+            Decision::Stalled,
+            // Never reached: a stalled nonce blocks everything behind it.
+            Decision::Confirmed,
+        ];
+        let (confirmed_through, outcome) = advance(&nonces, &decisions);
+        assert_eq!(confirmed_through, Some(2));
+        assert_eq!(outcome, PollOutcome::Progressed);
+    }
+
+    #[test]
+    fn advance_confirms_and_prunes_every_pending_nonce() {
+        let nonces = [1, 2, 3];
+        let decisions = [Decision::Confirmed, Decision::Confirmed, Decision::Confirmed];
+        let (confirmed_through, outcome) = advance(&nonces, &decisions);
+        assert_eq!(confirmed_through, Some(3));
+        assert_eq!(outcome, PollOutcome::Progressed);
+    }
+
+    #[test]
+    fn advance_aborts_the_earliest_permanent_failure_without_touching_later_nonces() {
+        let nonces = [1, 2, 3];
+        let decisions = [
+            Decision::Aborted(IndexError::permanent("MissingChain")),
+            // Never reached: the scan stops at the abort.
+            Decision::Confirmed,
+            Decision::Confirmed,
+        ];
+        let (confirmed_through, outcome) = advance(&nonces, &decisions);
+        assert_eq!(confirmed_through, None);
+        assert_eq!(
+            outcome,
+            PollOutcome::Aborted {
+                nonce: 1,
+                error: IndexError::permanent("MissingChain"),
+            }
+        );
+    }
+}
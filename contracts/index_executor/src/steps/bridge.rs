@@ -1,10 +1,12 @@
 use crate::account::AccountInfo;
-use crate::chain::ChainType;
+use crate::chain::{Chain, ChainType};
 use crate::context::Context;
+use crate::derive::derive_account;
+use crate::error::IndexError;
 use crate::storage::StorageClient;
 use crate::traits::Runner;
-use crate::tx;
-use alloc::{string::String, vec::Vec};
+use crate::tx::{self, GraphQlIndexer, IndexerBackend, RpcScanIndexer, TxIndexer};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use pink_subrpc::ExtraParam;
 use scale::{Decode, Encode};
 
@@ -26,12 +28,6 @@ pub struct BridgeStep {
     pub cap: u128,
     /// Flow of the step
     pub flow: u128,
-    /// Original relayer account balance of asset on source chain
-    /// Should be set when initializing task
-    pub b0: Option<u128>,
-    /// Original relayer account balance of asset on dest chain
-    /// Should be set when initializing task
-    pub b1: Option<u128>,
     /// Bridge amount
     pub amount: u128,
     /// Recipient account on dest chain
@@ -52,31 +48,62 @@ impl Runner for BridgeStep {
         nonce: u64,
         context: &Context,
         _client: Option<&StorageClient>,
-    ) -> Result<bool, &'static str> {
+    ) -> Result<bool, IndexError> {
         let worker_account = AccountInfo::from(context.signer);
 
         // TODO. query off-chain indexer directly get the execution result
 
+        // An unsupported/misconfigured source chain can never succeed no matter how many
+        // times it's retried, so reject it up front as permanent rather than letting the
+        // network calls below fail and get classified as a transient (retryable) error.
+        context
+            .registry
+            .get_chain(self.source_chain.clone())
+            .ok_or_else(|| IndexError::permanent("MissingChain"))?;
+
         // 1. Check nonce
-        let onchain_nonce = worker_account.get_nonce(self.source_chain.clone(), context)?;
+        let onchain_nonce = worker_account
+            .get_nonce(self.source_chain.clone(), context)
+            .map_err(IndexError::transient)?;
         if onchain_nonce > nonce {
             return Ok(false);
         }
         // 2. Check balance
-        let onchain_balance =
-            worker_account.get_balance(self.source_chain.clone(), self.from.clone(), context)?;
+        let onchain_balance = worker_account
+            .get_balance(self.source_chain.clone(), self.from.clone(), context)
+            .map_err(IndexError::transient)?;
         Ok(onchain_balance >= self.amount)
     }
 
-    fn run(&self, nonce: u64, context: &Context) -> Result<Vec<u8>, &'static str> {
+    fn run(&self, nonce: u64, context: &Context) -> Result<Vec<u8>, IndexError> {
+        // A zero (or otherwise invalid) bridge amount is a bad step config, not a transient
+        // network hiccup - retrying it would just fail again the same way.
+        if self.amount == 0 {
+            return Err(IndexError::permanent("InvalidAmount"));
+        }
+
         let signer = context.signer;
-        let recipient = self.recipient.clone().ok_or("MissingRecipient")?;
+        // If the task didn't pre-supply a recipient for this hop, derive it from the worker's
+        // own identity in the format the dest chain expects.
+        let recipient = match self.recipient.clone() {
+            Some(recipient) => recipient,
+            None => {
+                let dest_chain = context
+                    .registry
+                    .get_chain(self.dest_chain.clone())
+                    .ok_or_else(|| IndexError::permanent("MissingChain"))?;
+                derive_account(&AccountInfo::from(signer), &dest_chain)
+            }
+        };
+        if recipient.is_empty() {
+            return Err(IndexError::permanent("InvalidRecipient"));
+        }
 
         pink_extension::debug!("Start to run bridge with nonce: {}", nonce);
         // Get executor according to `src_chain` and `des_chain`
         let executor = context
             .get_bridge_executor(self.source_chain.clone(), self.dest_chain.clone())
-            .ok_or("MissingExecutor")?;
+            .ok_or_else(|| IndexError::permanent("MissingExecutor"))?;
         pink_extension::debug!("Found bridge executor on {:?}", &self.source_chain);
 
         // Do bridge transfer operation
@@ -92,7 +119,7 @@ impl Runner for BridgeStep {
                     era: None,
                 },
             )
-            .map_err(|_| "BridgeFailed")?;
+            .map_err(|_| IndexError::transient("BridgeFailed"))?;
         pink_extension::info!(
             "Submit transaction to bridge asset {:?} from {:?} to {:?}, recipient: {:?}, amount: {:?}, tx id: {:?}",
             &hex::encode(&self.from),
@@ -107,33 +134,57 @@ impl Runner for BridgeStep {
 
     // By checking the nonce we can known whether the transaction has been executed or not,
     // and with help of off-chain indexer, we can get the relevant transaction's execution result.
-    fn check(&self, nonce: u64, context: &Context) -> Result<bool, &'static str> {
+    fn check(&self, nonce: u64, context: &Context) -> Result<bool, IndexError> {
         let worker_account = AccountInfo::from(context.signer);
 
         // Query off-chain indexer directly get the execution result
         let chain = &context
             .registry
             .get_chain(self.source_chain.clone())
-            .ok_or("MissingChain")?;
+            .ok_or_else(|| IndexError::permanent("MissingChain"))?;
         let account = match chain.chain_type {
             ChainType::Evm => worker_account.account20.to_vec(),
             ChainType::Sub => worker_account.account32.to_vec(),
         };
 
-        if tx::check_tx(&chain.tx_indexer_url, &account, nonce)? {
-            // Check balance change on source chain and dest chain
-            let latest_b0 = worker_account.get_balance(
-                self.source_chain.clone(),
-                self.from.clone(),
-                context,
-            )?;
-            let latest_b1 =
-                worker_account.get_balance(self.dest_chain.clone(), self.to.clone(), context)?;
-            let b0 = self.b0.ok_or("MissingB0")?;
-            let b1 = self.b1.ok_or("MissingB1")?;
+        if tx::check_tx(tx_indexer(chain).as_ref(), &account, nonce)? {
+            // The source-chain tx succeeded, but that alone doesn't prove the funds arrived
+            // on the dest chain: comparing balance deltas is racy against unrelated deposits,
+            // fee refunds or concurrent jobs touching the same account. Instead look for the
+            // destination-chain deposit event the bridge itself produces, keyed by recipient,
+            // asset and the net amount (bridged amount minus the declared fee).
+            let dest_chain = context
+                .registry
+                .get_chain(self.dest_chain.clone())
+                .ok_or_else(|| IndexError::permanent("MissingChain"))?;
+            let recipient = match self.recipient.clone() {
+                Some(recipient) => recipient,
+                None => derive_account(&worker_account, &dest_chain),
+            };
+            let net_amount = self
+                .amount
+                .checked_sub(self.fee)
+                .ok_or_else(|| IndexError::permanent("InvalidFee"))?;
 
-            return Ok((b0 - latest_b0) == self.amount && latest_b1 > b1);
+            return tx::check_completion(
+                tx_indexer(&dest_chain).as_ref(),
+                &recipient,
+                &self.to,
+                net_amount,
+            );
         }
         Ok(false)
     }
+}
+
+/// Pick the off-chain indexer backend for `chain`, resolved from the chain's own
+/// `tx_indexer_backend` rather than guessed from whether `tx_indexer_url` happens to be set -
+/// an unconfigured URL is an operator mistake, not an implicit opt-in to RPC scanning.
+fn tx_indexer(chain: &Chain) -> Box<dyn TxIndexer> {
+    match chain.tx_indexer_backend {
+        IndexerBackend::GraphQl => Box::new(GraphQlIndexer::new(chain.tx_indexer_url.clone())),
+        IndexerBackend::RpcScan => {
+            Box::new(RpcScanIndexer::new(chain.endpoint.clone(), chain.chain_type))
+        }
+    }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use super::context::Context;
+use super::error::IndexError;
 use super::storage::StorageClient;
 use alloc::vec::Vec;
 
@@ -13,13 +14,13 @@ pub trait Runner {
         nonce: u64,
         context: &Context,
         client: Option<&StorageClient>,
-    ) -> Result<bool, &'static str>;
+    ) -> Result<bool, IndexError>;
 
     /// Execute a job, basically send a transaction to blockchain, and return tx id.
-    fn run(&self, nonce: u64, context: &Context) -> Result<Vec<u8>, &'static str>;
+    fn run(&self, nonce: u64, context: &Context) -> Result<Vec<u8>, IndexError>;
 
     /// Check if a job is already executed successfully when executing the job.
     ///
     /// Only when the transaction was successfully executed, it can return `true`
-    fn check(&self, nonce: u64, context: &Context) -> Result<bool, &'static str>;
+    fn check(&self, nonce: u64, context: &Context) -> Result<bool, IndexError>;
 }
@@ -0,0 +1,223 @@
+use super::{DepositEvent, Transaction, TxIndexer};
+use crate::error::IndexError;
+use alloc::{format, string::String, vec, vec::Vec};
+use pink_extension::http_req;
+use scale::Decode;
+use serde::Deserialize;
+
+/// Squid-style GraphQL off-chain indexer, queried with a
+/// `transactions(where: {nonce_eq, account_eq})` query.
+#[derive(Clone)]
+pub struct GraphQlIndexer {
+    url: String,
+}
+
+impl GraphQlIndexer {
+    pub fn new(url: String) -> Self {
+        Self { url }
+    }
+}
+
+impl TxIndexer for GraphQlIndexer {
+    fn get_tx(&self, account: &[u8], nonce: u64) -> Result<Option<Transaction>, IndexError> {
+        get_tx(&self.url, account, nonce)
+    }
+
+    fn get_deposit_event(
+        &self,
+        recipient: &[u8],
+        asset: &[u8],
+        amount: u128,
+    ) -> Result<Option<DepositEvent>, IndexError> {
+        get_deposit_event(&self.url, recipient, asset, amount)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+struct Tx {
+    pub id: String,
+    pub account: String,
+    pub nonce: u64,
+    pub result: bool,
+    pub block_number: u64,
+    pub timestamp: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+struct QueryResult {
+    transactions: Vec<Tx>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+struct ResponseData {
+    data: QueryResult,
+}
+
+fn send_request(indexer: &str, query: &str) -> Result<Vec<u8>, IndexError> {
+    let content_length = format!("{}", query.len());
+    let headers: Vec<(String, String)> = vec![
+        ("Content-Type".into(), "application/json".into()),
+        ("Content-Length".into(), content_length),
+    ];
+    let response = http_req!("POST", indexer, query.into(), headers);
+
+    if response.status_code != 200 {
+        return Err(IndexError::transient("CallIndexerFailed"));
+    }
+
+    Ok(response.body)
+}
+
+fn get_tx(indexer: &str, account: &[u8], nonce: u64) -> Result<Option<Transaction>, IndexError> {
+    let account = format!("0x{}", hex::encode(account)).to_lowercase();
+    pink_extension::debug!("get_tx: account: {}, nonce: {}", account, nonce);
+    let query = format!(
+        r#"{{
+            "query": "query Query {{ transactions(where: {{nonce_eq: {nonce}, account_eq: \"{account}\" }}) {{ blockNumber id nonce result timestamp account }} }}",
+            "variables": null,
+            "operationName": "Query"
+        }}"#
+    );
+    let body = send_request(indexer, &query)?;
+    let response: ResponseData =
+        pink_json::from_slice(&body).map_err(|_| IndexError::corrupt("InvalidBody"))?;
+    let transactions = &response.data.transactions;
+
+    pink_extension::debug!("get_tx: got transaction: {:?}", transactions);
+
+    // No matching row means the tx genuinely hasn't landed yet - that's a legitimate
+    // "unconfirmed", not an error. More than one row for a single (account, nonce) means the
+    // indexer is in an inconsistent state and must never be treated as "unconfirmed", or the
+    // scheduler could resubmit a transfer that already succeeded.
+    match transactions.len() {
+        0 => return Ok(None),
+        1 => {}
+        _ => return Err(IndexError::corrupt("DuplicateTransaction")),
+    }
+
+    let tx = &transactions[0];
+
+    Ok(Some(Transaction {
+        block_number: tx.block_number,
+        id: tx.id.clone(),
+        nonce: tx.nonce,
+        result: tx.result,
+        timestamp: tx.timestamp.clone(),
+        account: hex::decode(&tx.account[2..]).map_err(|_| IndexError::corrupt("InvalidAddress"))?,
+    }))
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+struct Deposit {
+    pub block_number: u64,
+    pub recipient: String,
+    pub asset: String,
+    pub amount: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+struct DepositQueryResult {
+    deposits: Vec<Deposit>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+struct DepositResponseData {
+    data: DepositQueryResult,
+}
+
+fn get_deposit_event(
+    indexer: &str,
+    recipient: &[u8],
+    asset: &[u8],
+    amount: u128,
+) -> Result<Option<DepositEvent>, IndexError> {
+    let recipient = format!("0x{}", hex::encode(recipient)).to_lowercase();
+    let asset = format!("0x{}", hex::encode(asset)).to_lowercase();
+    pink_extension::debug!(
+        "get_deposit_event: recipient: {}, asset: {}, amount: {}",
+        recipient,
+        asset,
+        amount
+    );
+    let query = format!(
+        r#"{{
+            "query": "query Query {{ deposits(where: {{recipient_eq: \"{recipient}\", asset_eq: \"{asset}\", amount_eq: \"{amount}\" }}) {{ blockNumber recipient asset amount }} }}",
+            "variables": null,
+            "operationName": "Query"
+        }}"#
+    );
+    let body = send_request(indexer, &query)?;
+    let response: DepositResponseData =
+        pink_json::from_slice(&body).map_err(|_| IndexError::corrupt("InvalidBody"))?;
+    let deposits = &response.data.deposits;
+
+    pink_extension::debug!("get_deposit_event: got deposit: {:?}", deposits);
+
+    if deposits.is_empty() {
+        return Ok(None);
+    }
+
+    let deposit = &deposits[0];
+    Ok(Some(DepositEvent {
+        block_number: deposit.block_number,
+        recipient: hex::decode(&deposit.recipient[2..])
+            .map_err(|_| IndexError::corrupt("InvalidAddress"))?,
+        asset: hex::decode(&deposit.asset[2..]).map_err(|_| IndexError::corrupt("InvalidAddress"))?,
+        amount: deposit
+            .amount
+            .parse::<u128>()
+            .map_err(|_| IndexError::corrupt("InvalidAmount"))?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    #[ignore]
+    fn should_work() {
+        pink_extension_runtime::mock_ext::mock_all_ext();
+        let account = hex_literal::hex!("9ccbdac25ecda4d817b3aa0e020bc65f841c80c3");
+        let tx = get_tx("http://127.0.0.1:4350", &account, 1)
+            .unwrap()
+            .unwrap();
+        dbg!(&tx);
+        assert_eq!(tx.result, true);
+    }
+
+    #[test]
+    #[ignore]
+    fn should_find_completion_deposit_event() {
+        pink_extension_runtime::mock_ext::mock_all_ext();
+        let recipient = hex_literal::hex!("9ccbdac25ecda4d817b3aa0e020bc65f841c80c3");
+        let asset = hex_literal::hex!("0000000000000000000000000000000000000000");
+        let found = get_deposit_event("http://127.0.0.1:4350", &recipient, &asset, 1_000_000_000_000)
+            .unwrap()
+            .is_some();
+        assert!(found);
+    }
+
+    #[test]
+    #[ignore]
+    fn should_not_find_completion_deposit_event_for_wrong_amount() {
+        pink_extension_runtime::mock_ext::mock_all_ext();
+        let recipient = hex_literal::hex!("9ccbdac25ecda4d817b3aa0e020bc65f841c80c3");
+        let asset = hex_literal::hex!("0000000000000000000000000000000000000000");
+        let found = get_deposit_event("http://127.0.0.1:4350", &recipient, &asset, 1)
+            .unwrap()
+            .is_some();
+        assert!(!found);
+    }
+}
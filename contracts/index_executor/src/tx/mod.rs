@@ -0,0 +1,92 @@
+mod graphql;
+mod rpc_scan;
+
+pub use graphql::GraphQlIndexer;
+pub use rpc_scan::RpcScanIndexer;
+
+use crate::error::IndexError;
+use alloc::{string::String, vec::Vec};
+use scale::Decode;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub block_number: u64,
+    pub id: String,
+    pub nonce: u64,
+    pub result: bool,
+    // unix timestamp; `RpcScanIndexer` on Substrate chains stores the block number here instead
+    pub timestamp: String,
+    pub account: Vec<u8>,
+}
+
+/// A deposit observed on a destination chain, proving a bridged transfer actually arrived.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[serde(rename_all = "camelCase")]
+pub struct DepositEvent {
+    pub block_number: u64,
+    pub recipient: Vec<u8>,
+    pub asset: Vec<u8>,
+    pub amount: u128,
+}
+
+/// An off-chain transaction indexer backend for a worker account.
+///
+/// `GraphQlIndexer` covers chains with a deployed Squid-style indexer; `RpcScanIndexer` covers
+/// chains that don't have one by reconstructing the same [`Transaction`] record straight from
+/// the chain node.
+pub trait TxIndexer {
+    fn get_tx(&self, account: &[u8], nonce: u64) -> Result<Option<Transaction>, IndexError>;
+
+    /// Look for a deposit matching `recipient`/`asset`/`amount`. Only meaningful when called
+    /// against the *destination* chain's indexer - i.e. the other side of a bridge transfer.
+    fn get_deposit_event(
+        &self,
+        recipient: &[u8],
+        asset: &[u8],
+        amount: u128,
+    ) -> Result<Option<DepositEvent>, IndexError>;
+}
+
+/// Which [`TxIndexer`] backend a chain should be queried through. Set explicitly per chain
+/// (`Chain::tx_indexer_backend`) rather than inferred from whether `tx_indexer_url` happens to
+/// be populated, so a chain that simply hasn't had its Squid URL configured yet doesn't get
+/// silently downgraded to the RPC-scan backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexerBackend {
+    /// Query a Squid-style GraphQL indexer via [`GraphQlIndexer`].
+    GraphQl,
+    /// Reconstruct tx records straight from the chain node via [`RpcScanIndexer`].
+    RpcScan,
+}
+
+/// Return true if transaction is confirmed on chain.
+pub fn check_tx(
+    indexer: &dyn TxIndexer,
+    account: &[u8],
+    nonce: u64,
+) -> Result<bool, IndexError> {
+    // nonce from storage is one larger than the last tx's nonce
+    let tx = indexer.get_tx(account, nonce)?;
+    pink_extension::debug!("check_tx: tx record returned from indexer: {:?}", tx);
+    Ok(tx.map(|tx| tx.result).unwrap_or(false))
+}
+
+/// Return true if a deposit event matching `recipient`/`asset`/`amount` can be found through
+/// `indexer`, i.e. the bridged funds actually arrived.
+///
+/// This is deliberately event-based rather than a balance-delta comparison: a balance moving
+/// by roughly the right amount doesn't prove *this* transfer caused it (an unrelated deposit,
+/// a fee refund, or a concurrent job could too), while a matching deposit event is specific to
+/// the transfer being checked.
+pub fn check_completion(
+    indexer: &dyn TxIndexer,
+    recipient: &[u8],
+    asset: &[u8],
+    amount: u128,
+) -> Result<bool, IndexError> {
+    Ok(indexer.get_deposit_event(recipient, asset, amount)?.is_some())
+}
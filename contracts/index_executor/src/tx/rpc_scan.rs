@@ -0,0 +1,545 @@
+use super::{DepositEvent, Transaction, TxIndexer};
+use crate::chain::ChainType;
+use crate::error::IndexError;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use pink_extension::http_req;
+use pink_subrpc::{get_storage, storage::storage_prefix};
+
+/// How many recent blocks to walk back over looking for a landed transaction/extrinsic. A
+/// worker only ever has a handful of nonces in flight at once, so a tx that doesn't show up
+/// within this window almost certainly isn't going to - the caller should keep polling rather
+/// than assume "not confirmed", since it's still ahead of the on-chain nonce.
+const SCAN_WINDOW_BLOCKS: u64 = 64;
+
+/// Reconstructs a [`Transaction`] record straight from the chain node instead of an off-chain
+/// indexer, for chains that don't have a Squid deployed: `eth_getTransactionCount` plus a
+/// receipt/log lookup for EVM chains, `system_accountNextIndex` plus event scanning for
+/// Substrate chains.
+#[derive(Clone)]
+pub struct RpcScanIndexer {
+    endpoint: String,
+    chain_type: ChainType,
+}
+
+impl RpcScanIndexer {
+    pub fn new(endpoint: String, chain_type: ChainType) -> Self {
+        Self {
+            endpoint,
+            chain_type,
+        }
+    }
+}
+
+impl TxIndexer for RpcScanIndexer {
+    fn get_tx(&self, account: &[u8], nonce: u64) -> Result<Option<Transaction>, IndexError> {
+        match self.chain_type {
+            ChainType::Evm => get_tx_evm(&self.endpoint, account, nonce),
+            ChainType::Sub => get_tx_sub(&self.endpoint, account, nonce),
+        }
+    }
+
+    fn get_deposit_event(
+        &self,
+        recipient: &[u8],
+        asset: &[u8],
+        amount: u128,
+    ) -> Result<Option<DepositEvent>, IndexError> {
+        match self.chain_type {
+            ChainType::Evm => get_deposit_event_evm(&self.endpoint, recipient, asset, amount),
+            ChainType::Sub => get_deposit_event_sub(&self.endpoint, recipient, asset, amount),
+        }
+    }
+}
+
+fn rpc_call(endpoint: &str, method: &str, params: &str) -> Result<Vec<u8>, IndexError> {
+    let body = format!(
+        r#"{{"id":1,"jsonrpc":"2.0","method":"{method}","params":{params}}}"#
+    );
+    let content_length = format!("{}", body.len());
+    let headers: Vec<(String, String)> = vec![
+        ("Content-Type".into(), "application/json".into()),
+        ("Content-Length".into(), content_length),
+    ];
+    let response = http_req!("POST", endpoint, body.into(), headers);
+
+    if response.status_code != 200 {
+        return Err(IndexError::transient("CallNodeFailed"));
+    }
+
+    Ok(response.body)
+}
+
+// `eth_getTransactionCount(account, "latest")` tells us whether `nonce` has already landed;
+// once it has, we walk back from the chain tip looking for the block that carries it and pull
+// its receipt for the real status - a reverted tx still consumes a nonce, so the nonce check
+// alone can never tell success from failure.
+fn get_tx_evm(
+    endpoint: &str,
+    account: &[u8],
+    nonce: u64,
+) -> Result<Option<Transaction>, IndexError> {
+    let address = format!("0x{}", hex::encode(account)).to_lowercase();
+    let params = format!(r#"["{address}", "latest"]"#);
+    let onchain_nonce = parse_hex_quantity(&rpc_call(endpoint, "eth_getTransactionCount", &params)?)?;
+
+    if onchain_nonce <= nonce {
+        return Ok(None);
+    }
+
+    let latest = parse_hex_quantity(&rpc_call(endpoint, "eth_blockNumber", "[]")?)?;
+    let earliest = latest.saturating_sub(SCAN_WINDOW_BLOCKS);
+
+    for block_number in (earliest..=latest).rev() {
+        let block = get_block_by_number(endpoint, block_number)?;
+        let Some(tx_hash) = find_tx_hash(&block, &address, nonce)? else {
+            continue;
+        };
+        let receipt = get_tx_receipt(endpoint, &tx_hash)?;
+        return Ok(Some(Transaction {
+            block_number,
+            id: tx_hash,
+            nonce,
+            result: receipt_succeeded(&receipt)?,
+            timestamp: parse_json_hex_field(&block, "timestamp")?.to_string(),
+            account: account.to_vec(),
+        }));
+    }
+
+    // The nonce has landed on-chain but its block fell outside the scan window - keep retrying
+    // rather than reporting "not confirmed".
+    Err(IndexError::transient("TxOutsideScanWindow"))
+}
+
+fn get_block_by_number(endpoint: &str, block_number: u64) -> Result<pink_json::Value, IndexError> {
+    let params = format!(r#"["0x{block_number:x}", true]"#);
+    parse_rpc_result(&rpc_call(endpoint, "eth_getBlockByNumber", &params)?)
+}
+
+fn get_tx_receipt(endpoint: &str, tx_hash: &str) -> Result<pink_json::Value, IndexError> {
+    let params = format!(r#"["{tx_hash}"]"#);
+    parse_rpc_result(&rpc_call(endpoint, "eth_getTransactionReceipt", &params)?)
+}
+
+fn find_tx_hash(
+    block: &pink_json::Value,
+    address: &str,
+    nonce: u64,
+) -> Result<Option<String>, IndexError> {
+    let transactions = block["transactions"]
+        .as_array()
+        .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+    for tx in transactions {
+        let from = tx["from"].as_str().unwrap_or_default().to_lowercase();
+        if from != address {
+            continue;
+        }
+        if parse_json_hex_field(tx, "nonce")? != nonce {
+            continue;
+        }
+        let hash = tx["hash"]
+            .as_str()
+            .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+        return Ok(Some(String::from(hash)));
+    }
+    Ok(None)
+}
+
+fn receipt_succeeded(receipt: &pink_json::Value) -> Result<bool, IndexError> {
+    let status = receipt["status"]
+        .as_str()
+        .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+    Ok(status == "0x1")
+}
+
+/// `keccak256("Transfer(address,address,uint256)")` - the standard ERC20 transfer event topic.
+const ERC20_TRANSFER_TOPIC: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+// `eth_getLogs` filtered to `asset`'s ERC20 `Transfer` events landing on `recipient`, scanning
+// the same recent-block window `get_tx_evm` uses. A deposit that did land but fell outside the
+// window is indistinguishable from "not yet landed" here, so we report `None` rather than erroring
+// - the caller just keeps polling.
+fn get_deposit_event_evm(
+    endpoint: &str,
+    recipient: &[u8],
+    asset: &[u8],
+    amount: u128,
+) -> Result<Option<DepositEvent>, IndexError> {
+    let token = format!("0x{}", hex::encode(asset)).to_lowercase();
+    let recipient_topic = format!("0x{:0>64}", hex::encode(recipient));
+    let latest = parse_hex_quantity(&rpc_call(endpoint, "eth_blockNumber", "[]")?)?;
+    let earliest = latest.saturating_sub(SCAN_WINDOW_BLOCKS);
+
+    let params = format!(
+        r#"[{{"fromBlock":"0x{earliest:x}","toBlock":"0x{latest:x}","address":"{token}","topics":["{ERC20_TRANSFER_TOPIC}",null,"{recipient_topic}"]}}]"#
+    );
+    let logs = parse_rpc_result(&rpc_call(endpoint, "eth_getLogs", &params)?)?;
+    let logs = logs.as_array().ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+
+    for log in logs {
+        let data = log["data"]
+            .as_str()
+            .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+        let value = u128::from_str_radix(data.trim_start_matches("0x"), 16)
+            .map_err(|_| IndexError::corrupt("InvalidBody"))?;
+        if value != amount {
+            continue;
+        }
+        return Ok(Some(DepositEvent {
+            block_number: parse_json_hex_field(log, "blockNumber")?,
+            recipient: recipient.to_vec(),
+            asset: asset.to_vec(),
+            amount,
+        }));
+    }
+    Ok(None)
+}
+
+/// Conventional index of the `System` pallet within `RuntimeEvent`/`System::Events` - true for
+/// every FRAME-based chain this executor bridges through.
+const SYSTEM_PALLET_EVENT_INDEX: u8 = 0;
+/// `frame_system::Event::ExtrinsicSuccess` variant index.
+const EXTRINSIC_SUCCESS_INDEX: u8 = 0;
+/// `frame_system::Event::ExtrinsicFailed` variant index.
+const EXTRINSIC_FAILED_INDEX: u8 = 1;
+
+// `system_accountNextIndex(account)` tells us whether `nonce` has already landed; once it has,
+// we walk back from the chain tip looking for the extrinsic that carries it and check its
+// `System` success/failure event for the real status - a failed extrinsic still consumes a
+// nonce, so the nonce check alone can never tell success from failure.
+fn get_tx_sub(
+    endpoint: &str,
+    account: &[u8],
+    nonce: u64,
+) -> Result<Option<Transaction>, IndexError> {
+    let address = format!("0x{}", hex::encode(account));
+    let params = format!(r#"["{address}"]"#);
+    let onchain_nonce = parse_hex_quantity(&rpc_call(endpoint, "system_accountNextIndex", &params)?)?;
+
+    if onchain_nonce <= nonce {
+        return Ok(None);
+    }
+
+    let header = parse_rpc_result(&rpc_call(endpoint, "chain_getHeader", "[]")?)?;
+    let latest = parse_json_hex_field(&header, "number")?;
+    let earliest = latest.saturating_sub(SCAN_WINDOW_BLOCKS);
+
+    for block_number in (earliest..=latest).rev() {
+        let block_hash = get_block_hash(endpoint, block_number)?;
+        let block = get_block(endpoint, &block_hash)?;
+        let Some(extrinsic_index) = find_extrinsic_index(&block, account, nonce)? else {
+            continue;
+        };
+        return Ok(Some(Transaction {
+            block_number,
+            // We don't have the extrinsic's own hash without re-encoding and hashing it
+            // ourselves, so use the containing block's hash as a stand-in identifier.
+            id: block_hash,
+            nonce,
+            result: extrinsic_succeeded(endpoint, &block_hash, extrinsic_index)?,
+            // The block's timestamp isn't fetched; the block number is recorded in its place.
+            timestamp: block_number.to_string(),
+            account: account.to_vec(),
+        }));
+    }
+
+    // The nonce has landed on-chain but its block fell outside the scan window - keep retrying
+    // rather than reporting "not confirmed".
+    Err(IndexError::transient("TxOutsideScanWindow"))
+}
+
+fn get_block_hash(endpoint: &str, block_number: u64) -> Result<String, IndexError> {
+    let params = format!("[{block_number}]");
+    let result = parse_rpc_result(&rpc_call(endpoint, "chain_getBlockHash", &params)?)?;
+    result
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| IndexError::corrupt("InvalidBody"))
+}
+
+fn get_block(endpoint: &str, block_hash: &str) -> Result<pink_json::Value, IndexError> {
+    let params = format!(r#"["{block_hash}"]"#);
+    parse_rpc_result(&rpc_call(endpoint, "chain_getBlock", &params)?)
+}
+
+/// Find `account`'s extrinsic for `nonce` within `block` and return its index, by locating the
+/// embedded `AccountId32` signer (unique enough at 32 bytes to never collide) and decoding the
+/// `Compact<u64>` nonce that follows its signature.
+///
+/// Only handles the common `MultiAddress::Id` signer + immortal-era shape; anything else is
+/// skipped (`None`) rather than mis-parsed, so the caller just keeps scanning older blocks.
+fn find_extrinsic_index(
+    block: &pink_json::Value,
+    account: &[u8],
+    nonce: u64,
+) -> Result<Option<usize>, IndexError> {
+    let extrinsics = block["block"]["extrinsics"]
+        .as_array()
+        .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+
+    for (index, extrinsic) in extrinsics.iter().enumerate() {
+        let raw = extrinsic
+            .as_str()
+            .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+        let bytes =
+            hex::decode(raw.trim_start_matches("0x")).map_err(|_| IndexError::corrupt("InvalidBody"))?;
+        if signed_extrinsic_nonce(&bytes, account) == Some(nonce) {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+/// Extrinsic format version byte for a signed extrinsic (the top bit marks "signed", the
+/// lower bits are the format version).
+const SIGNED_EXTRINSIC_VERSION: u8 = 0x84;
+/// Byte length of `MultiSignature`'s payload for each signing scheme, keyed by its enum index
+/// (`Ed25519 = 0`, `Sr25519 = 1`, `Ecdsa = 2`).
+const SIGNATURE_LENGTHS: [usize; 3] = [64, 64, 65];
+
+fn signed_extrinsic_nonce(bytes: &[u8], account: &[u8]) -> Option<u64> {
+    let mut cursor = bytes;
+    let _length: scale::Compact<u32> = scale::Decode::decode(&mut cursor).ok()?;
+    if *cursor.first()? != SIGNED_EXTRINSIC_VERSION {
+        return None;
+    }
+    cursor = &cursor[1..];
+    // `MultiAddress::Id(AccountId32)` - variant 0 followed by the raw 32-byte account id.
+    if *cursor.first()? != 0 {
+        return None;
+    }
+    cursor = cursor.get(1..)?;
+    if cursor.len() < 32 {
+        return None;
+    }
+    let (signer, rest) = cursor.split_at(32);
+    if signer != account {
+        return None;
+    }
+    cursor = rest;
+    // `MultiSignature` - an enum tag byte selecting the scheme, then its fixed-length payload.
+    let scheme = *cursor.first()? as usize;
+    let sig_len = *SIGNATURE_LENGTHS.get(scheme)?;
+    cursor = cursor.get(1 + sig_len..)?;
+    // `Era` - only the immortal case (a single `0x00` byte) is handled; mortal eras are left
+    // unparsed.
+    if *cursor.first()? != 0 {
+        return None;
+    }
+    cursor = &cursor[1..];
+    let nonce: scale::Compact<u64> = scale::Decode::decode(&mut cursor).ok()?;
+    Some(nonce.0)
+}
+
+/// Look for `System::Events`' `ExtrinsicSuccess`/`ExtrinsicFailed` record for
+/// `extrinsic_index` in the block at `block_hash`.
+///
+/// This is a byte-pattern search rather than a full sequential decode: without the chain's
+/// runtime metadata we can't know each event's payload length to skip past it, but
+/// `Phase::ApplyExtrinsic(idx)` immediately followed by the system pallet/event-variant tag is
+/// distinctive enough (7 exact bytes) to search for directly.
+fn extrinsic_succeeded(
+    endpoint: &str,
+    block_hash: &str,
+    extrinsic_index: usize,
+) -> Result<bool, IndexError> {
+    let events = get_storage_at(endpoint, &storage_prefix("System", "Events"), block_hash)?;
+
+    let mut prefix = Vec::with_capacity(6);
+    prefix.push(0u8); // Phase::ApplyExtrinsic
+    prefix.extend_from_slice(&(extrinsic_index as u32).to_le_bytes());
+    prefix.push(SYSTEM_PALLET_EVENT_INDEX);
+
+    let mut success = prefix.clone();
+    success.push(EXTRINSIC_SUCCESS_INDEX);
+    if contains(&events, &success) {
+        return Ok(true);
+    }
+
+    let mut failed = prefix;
+    failed.push(EXTRINSIC_FAILED_INDEX);
+    if contains(&events, &failed) {
+        return Ok(false);
+    }
+
+    Err(IndexError::corrupt("MissingExtrinsicEvent"))
+}
+
+fn get_storage_at(endpoint: &str, key: &[u8], block_hash: &str) -> Result<Vec<u8>, IndexError> {
+    let block_hash_bytes =
+        hex::decode(block_hash.trim_start_matches("0x")).map_err(|_| IndexError::corrupt("InvalidBody"))?;
+    get_storage(endpoint, key, Some(block_hash_bytes))
+        .map_err(|_| IndexError::transient("CallNodeFailed"))?
+        .ok_or_else(|| IndexError::transient("CallNodeFailed"))
+}
+
+// Walks the same recent-block window `get_tx_sub` uses, looking for a `System::Events` record
+// carrying `asset`'s bytes, then `recipient`'s, then `amount`'s little-endian encoding, all
+// contiguous. Like `extrinsic_succeeded`, this is a byte-pattern search rather than a full
+// decode, since we don't have the runtime metadata needed to walk the event `Vec` generically;
+// a deposit that landed outside the window reports `None` rather than an error, so the caller
+// just keeps polling.
+fn get_deposit_event_sub(
+    endpoint: &str,
+    recipient: &[u8],
+    asset: &[u8],
+    amount: u128,
+) -> Result<Option<DepositEvent>, IndexError> {
+    let header = parse_rpc_result(&rpc_call(endpoint, "chain_getHeader", "[]")?)?;
+    let latest = parse_json_hex_field(&header, "number")?;
+    let earliest = latest.saturating_sub(SCAN_WINDOW_BLOCKS);
+
+    let needle = deposit_needle(asset, recipient, amount);
+
+    for block_number in (earliest..=latest).rev() {
+        let block_hash = get_block_hash(endpoint, block_number)?;
+        let events = get_storage_at(endpoint, &storage_prefix("System", "Events"), &block_hash)?;
+        if contains(&events, &needle) {
+            return Ok(Some(DepositEvent {
+                block_number,
+                recipient: recipient.to_vec(),
+                asset: asset.to_vec(),
+                amount,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// Byte pattern searched for within `System::Events` by `get_deposit_event_sub`. `asset` is
+/// included (not just `recipient`/`amount`) so two deposits that happen to share a recipient and
+/// amount but differ in asset aren't conflated - the same false positive `get_deposit_event_evm`
+/// avoids by filtering `eth_getLogs` on the token contract address.
+fn deposit_needle(asset: &[u8], recipient: &[u8], amount: u128) -> Vec<u8> {
+    let mut needle = asset.to_vec();
+    needle.extend_from_slice(recipient);
+    needle.extend_from_slice(&amount.to_le_bytes());
+    needle
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+fn parse_rpc_result(body: &[u8]) -> Result<pink_json::Value, IndexError> {
+    let response: pink_json::Value =
+        pink_json::from_slice(body).map_err(|_| IndexError::corrupt("InvalidBody"))?;
+    Ok(response["result"].clone())
+}
+
+fn parse_json_hex_field(value: &pink_json::Value, field: &str) -> Result<u64, IndexError> {
+    let raw = value[field]
+        .as_str()
+        .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+    u64::from_str_radix(raw.trim_start_matches("0x"), 16).map_err(|_| IndexError::corrupt("InvalidBody"))
+}
+
+fn parse_hex_quantity(body: &[u8]) -> Result<u64, IndexError> {
+    let response: pink_json::Value =
+        pink_json::from_slice(body).map_err(|_| IndexError::corrupt("InvalidBody"))?;
+    let result = response["result"]
+        .as_str()
+        .ok_or_else(|| IndexError::corrupt("InvalidBody"))?;
+    u64::from_str_radix(result.trim_start_matches("0x"), 16)
+        .or_else(|_| result.parse::<u64>())
+        .map_err(|_| IndexError::corrupt("InvalidBody"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Encode;
+
+    #[test]
+    fn parses_hex_quantity() {
+        let body = br#"{"id":1,"jsonrpc":"2.0","result":"0x2a"}"#;
+        assert_eq!(parse_hex_quantity(body).unwrap(), 42);
+    }
+
+    #[test]
+    fn parses_decimal_quantity_fallback() {
+        let body = br#"{"id":1,"jsonrpc":"2.0","result":"42"}"#;
+        assert_eq!(parse_hex_quantity(body).unwrap(), 42);
+    }
+
+    #[test]
+    fn rejects_missing_result_field() {
+        let body = br#"{"id":1,"jsonrpc":"2.0"}"#;
+        assert_eq!(
+            parse_hex_quantity(body).unwrap_err(),
+            IndexError::corrupt("InvalidBody")
+        );
+    }
+
+    #[test]
+    fn rejects_non_numeric_result() {
+        let body = br#"{"id":1,"jsonrpc":"2.0","result":"not-a-number"}"#;
+        assert_eq!(
+            parse_hex_quantity(body).unwrap_err(),
+            IndexError::corrupt("InvalidBody")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        let body = b"not json";
+        assert_eq!(
+            parse_hex_quantity(body).unwrap_err(),
+            IndexError::corrupt("InvalidBody")
+        );
+    }
+
+    #[test]
+    fn finds_byte_pattern_inside_haystack() {
+        assert!(contains(&[1, 2, 3, 4, 5], &[3, 4]));
+        assert!(!contains(&[1, 2, 3, 4, 5], &[4, 3]));
+    }
+
+    #[test]
+    fn deposit_needle_distinguishes_same_recipient_and_amount_by_asset() {
+        let recipient = [1u8; 32];
+        let amount = 1_000_000_000_000u128;
+
+        let mut events = vec![0xAA, 0xBB];
+        events.extend_from_slice(&deposit_needle(&[0u8], &recipient, amount));
+        events.push(0xCC);
+
+        assert!(contains(&events, &deposit_needle(&[0u8], &recipient, amount)));
+        assert!(!contains(&events, &deposit_needle(&[1u8], &recipient, amount)));
+    }
+
+    fn signed_extrinsic_v4(account: &[u8; 32], nonce: u64) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.push(SIGNED_EXTRINSIC_VERSION);
+        body.push(0); // MultiAddress::Id
+        body.extend_from_slice(account);
+        body.push(1); // MultiSignature::Sr25519
+        body.extend_from_slice(&[0u8; 64]); // signature payload
+        body.push(0); // Era::Immortal
+        body.extend_from_slice(&scale::Compact(nonce).encode());
+        body.extend_from_slice(&scale::Compact(0u128).encode()); // tip
+        body.extend_from_slice(&[0xAB, 0xCD]); // opaque call bytes
+
+        let mut extrinsic = scale::Compact(body.len() as u32).encode();
+        extrinsic.extend_from_slice(&body);
+        extrinsic
+    }
+
+    #[test]
+    fn decodes_nonce_for_matching_signer() {
+        let account = [7u8; 32];
+        let extrinsic = signed_extrinsic_v4(&account, 42);
+        assert_eq!(signed_extrinsic_nonce(&extrinsic, &account), Some(42));
+    }
+
+    #[test]
+    fn ignores_extrinsic_signed_by_a_different_account() {
+        let extrinsic = signed_extrinsic_v4(&[7u8; 32], 42);
+        assert_eq!(signed_extrinsic_nonce(&extrinsic, &[9u8; 32]), None);
+    }
+}